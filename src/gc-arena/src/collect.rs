@@ -0,0 +1,46 @@
+use std::any::Any;
+
+use crate::context::CollectionContext;
+
+/// Types that can (transitively) hold `Gc` pointers and participate in
+/// garbage collection.
+///
+/// # Safety
+///
+/// `needs_trace` must return `true` unless the type can never, even
+/// indirectly, hold a `Gc` pointer, and `trace` must visit every `Gc`
+/// reachable from `self` (whether directly, by enqueuing it through
+/// `CollectionContext`, or by forwarding to a field's own `trace`).
+/// Under-tracing lets the collector reclaim something still reachable.
+pub unsafe trait Collect {
+    /// Visit every `Gc` pointer reachable from `self`.
+    ///
+    /// The default is a no-op, which is correct for any type whose
+    /// `needs_trace` is `false`.
+    fn trace(&self, cc: CollectionContext) {
+        let _ = cc;
+    }
+
+    /// Whether this type can ever hold a `Gc` pointer, and thus needs
+    /// `trace` called on it at all.
+    ///
+    /// Defaults to the conservative `true`; types that provably never hold
+    /// a `Gc` (see `static_collect!`/`unsafe_empty_collect!`) override it to
+    /// `false` so the collector can skip tracing them entirely.
+    fn needs_trace() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+
+    /// Attempt to view `self` as `&dyn Any`, for heterogeneous `Gc<dyn
+    /// Collect>` containers that need to recover a concrete type.
+    ///
+    /// Defaults to `None`. Only `'static` types can implement this
+    /// soundly, so `#[derive(Collect)]` only generates a `Some(self)`
+    /// override for types that are actually `'static`.
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+}
@@ -1,8 +1,19 @@
+use std::any::Any;
+use std::borrow::Cow;
 use std::cell::{Cell, RefCell};
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::ffi::OsString;
 use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+use std::ops::{Range, RangeInclusive};
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::collect::Collect;
 use crate::context::CollectionContext;
@@ -55,6 +66,22 @@ static_collect!(isize);
 static_collect!(f32);
 static_collect!(f64);
 static_collect!(String);
+static_collect!(OsString);
+static_collect!(Path);
+static_collect!(PathBuf);
+static_collect!(Duration);
+static_collect!(NonZeroI8);
+static_collect!(NonZeroI16);
+static_collect!(NonZeroI32);
+static_collect!(NonZeroI64);
+static_collect!(NonZeroI128);
+static_collect!(NonZeroIsize);
+static_collect!(NonZeroU8);
+static_collect!(NonZeroU16);
+static_collect!(NonZeroU32);
+static_collect!(NonZeroU64);
+static_collect!(NonZeroU128);
+static_collect!(NonZeroUsize);
 
 unsafe impl<'a, T: ?Sized> Collect for &'a T {
     #[inline]
@@ -70,11 +97,106 @@ unsafe impl<'a, T: ?Sized> Collect for &'a mut T {
     }
 }
 
+unsafe impl<T: ?Sized> Collect for PhantomData<T> {
+    #[inline]
+    fn needs_trace() -> bool {
+        false
+    }
+}
+
+unsafe impl<T> Collect for Wrapping<T>
+where
+    T: Collect,
+{
+    #[inline]
+    fn needs_trace() -> bool {
+        T::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        self.0.trace(cc)
+    }
+}
+
+unsafe impl<T> Collect for Saturating<T>
+where
+    T: Collect,
+{
+    #[inline]
+    fn needs_trace() -> bool {
+        T::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        self.0.trace(cc)
+    }
+}
+
+unsafe impl<'a, B> Collect for Cow<'a, B>
+where
+    B: ?Sized + ToOwned + 'static,
+    B::Owned: Collect,
+{
+    #[inline]
+    fn needs_trace() -> bool {
+        B::Owned::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        if let Cow::Owned(o) = self {
+            o.trace(cc)
+        }
+    }
+}
+
+unsafe impl<T> Collect for Range<T>
+where
+    T: Collect,
+{
+    #[inline]
+    fn needs_trace() -> bool {
+        T::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        self.start.trace(cc);
+        self.end.trace(cc);
+    }
+}
+
+unsafe impl<T> Collect for RangeInclusive<T>
+where
+    T: Collect,
+{
+    #[inline]
+    fn needs_trace() -> bool {
+        T::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        self.start().trace(cc);
+        self.end().trace(cc);
+    }
+}
+
+// `Collect::as_any` defaults to `None`; these transparent wrappers forward to
+// their inner value so a `Gc<dyn Collect>` behind one of them can still be
+// downcast.
 unsafe impl<T: ?Sized + Collect> Collect for Box<T> {
     #[inline]
     fn trace(&self, cc: CollectionContext) {
         (**self).trace(cc)
     }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn Any> {
+        (**self).as_any()
+    }
 }
 
 unsafe impl<T: Collect> Collect for Box<[T]> {
@@ -134,6 +256,51 @@ unsafe impl<T: Collect> Collect for Vec<T> {
     }
 }
 
+unsafe impl<T: Collect> Collect for VecDeque<T> {
+    #[inline]
+    fn needs_trace() -> bool {
+        T::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        for t in self {
+            t.trace(cc)
+        }
+    }
+}
+
+unsafe impl<T: Collect> Collect for LinkedList<T> {
+    #[inline]
+    fn needs_trace() -> bool {
+        T::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        for t in self {
+            t.trace(cc)
+        }
+    }
+}
+
+unsafe impl<T> Collect for BinaryHeap<T>
+where
+    T: Ord + Collect,
+{
+    #[inline]
+    fn needs_trace() -> bool {
+        T::needs_trace()
+    }
+
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        for t in self {
+            t.trace(cc)
+        }
+    }
+}
+
 unsafe impl<K, V, S> Collect for HashMap<K, V, S>
 where
     K: Eq + Hash + Collect,
@@ -216,6 +383,11 @@ where
     fn trace(&self, cc: CollectionContext) {
         (**self).trace(cc);
     }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn Any> {
+        (**self).as_any()
+    }
 }
 
 unsafe impl<T> Collect for Arc<T>
@@ -226,6 +398,11 @@ where
     fn trace(&self, cc: CollectionContext) {
         (**self).trace(cc);
     }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn Any> {
+        (**self).as_any()
+    }
 }
 
 unsafe impl<T> Collect for Cell<T>
@@ -248,57 +425,23 @@ where
     }
 }
 
-macro_rules! impl_array_collect {
-    ($sz:expr) => {
-        unsafe impl<T: Collect> Collect for [T; $sz] {
-            #[inline]
-            fn needs_trace() -> bool {
-                T::needs_trace()
-            }
+// `[T; 0]` falls out of this impl for free: `needs_trace` is `false` and the
+// (empty) loop in `trace` never runs, so there's no arbitrary size ceiling
+// like the old per-length macro had.
+unsafe impl<T: Collect, const N: usize> Collect for [T; N] {
+    #[inline]
+    fn needs_trace() -> bool {
+        N != 0 && T::needs_trace()
+    }
 
-            #[inline]
-            fn trace(&self, cc: CollectionContext) {
-                for t in self {
-                    t.trace(cc)
-                }
-            }
+    #[inline]
+    fn trace(&self, cc: CollectionContext) {
+        for t in self {
+            t.trace(cc)
         }
-    };
+    }
 }
 
-impl_array_collect!(1);
-impl_array_collect!(2);
-impl_array_collect!(3);
-impl_array_collect!(4);
-impl_array_collect!(5);
-impl_array_collect!(6);
-impl_array_collect!(7);
-impl_array_collect!(8);
-impl_array_collect!(9);
-impl_array_collect!(10);
-impl_array_collect!(11);
-impl_array_collect!(12);
-impl_array_collect!(13);
-impl_array_collect!(14);
-impl_array_collect!(15);
-impl_array_collect!(16);
-impl_array_collect!(17);
-impl_array_collect!(18);
-impl_array_collect!(19);
-impl_array_collect!(20);
-impl_array_collect!(21);
-impl_array_collect!(22);
-impl_array_collect!(23);
-impl_array_collect!(24);
-impl_array_collect!(25);
-impl_array_collect!(26);
-impl_array_collect!(27);
-impl_array_collect!(28);
-impl_array_collect!(29);
-impl_array_collect!(30);
-impl_array_collect!(31);
-impl_array_collect!(32);
-
 macro_rules! impl_tuple {
     () => (
         unsafe impl Collect for () {
@@ -360,4 +503,83 @@ unsafe impl<T: Collect, N: ArrayLength<T>> Collect for HVec<T, N> {
             t.trace(cc)
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    struct NeedsTrace;
+
+    unsafe impl Collect for NeedsTrace {
+        fn needs_trace() -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn vec_deque_propagates_needs_trace() {
+        assert!(VecDeque::<NeedsTrace>::needs_trace());
+        assert!(!VecDeque::<u32>::needs_trace());
+    }
+
+    #[test]
+    fn linked_list_propagates_needs_trace() {
+        assert!(LinkedList::<NeedsTrace>::needs_trace());
+        assert!(!LinkedList::<u32>::needs_trace());
+    }
+
+    #[test]
+    fn binary_heap_propagates_needs_trace() {
+        assert!(BinaryHeap::<NeedsTrace>::needs_trace());
+        assert!(!BinaryHeap::<u32>::needs_trace());
+    }
+
+    #[test]
+    fn cow_propagates_needs_trace() {
+        assert!(!Cow::<str>::needs_trace());
+    }
+
+    #[test]
+    fn phantom_data_never_needs_trace() {
+        assert!(!PhantomData::<NeedsTrace>::needs_trace());
+    }
+
+    #[test]
+    fn wrapping_and_saturating_propagate_needs_trace() {
+        assert!(Wrapping::<NeedsTrace>::needs_trace());
+        assert!(!Wrapping::<u32>::needs_trace());
+        assert!(Saturating::<NeedsTrace>::needs_trace());
+        assert!(!Saturating::<u32>::needs_trace());
+    }
+
+    #[test]
+    fn range_propagates_needs_trace() {
+        assert!(Range::<NeedsTrace>::needs_trace());
+        assert!(!Range::<u32>::needs_trace());
+        assert!(RangeInclusive::<NeedsTrace>::needs_trace());
+        assert!(!RangeInclusive::<u32>::needs_trace());
+    }
+
+    #[test]
+    fn static_types_never_need_trace() {
+        assert!(!Duration::needs_trace());
+        assert!(!Path::needs_trace());
+        assert!(!PathBuf::needs_trace());
+        assert!(!OsString::needs_trace());
+        assert!(!NonZeroU32::needs_trace());
+    }
+
+    #[test]
+    fn zero_length_array_never_needs_trace() {
+        assert!(!<[NeedsTrace; 0]>::needs_trace());
+        assert!(!<[u32; 0]>::needs_trace());
+    }
+
+    #[test]
+    fn array_above_32_propagates_needs_trace() {
+        assert!(<[NeedsTrace; 48]>::needs_trace());
+        assert!(!<[u32; 48]>::needs_trace());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use std::cell::RefCell;
+
+use crate::collect::Collect;
+use crate::tracer::{GcPtr, Tracer};
+
+/// Handed to `Collect::trace` implementations so they can register the
+/// values reachable from `self` with the current collection cycle.
+///
+/// `CollectionContext` never traces anything itself. `enqueue` just remembers
+/// a type-erased pointer to a value on a shared [`Tracer`]; the collector
+/// drains that queue afterwards (see [`trace_all`]), calling `trace` on each
+/// pointer in turn. Because that drain loop — not a chain of nested `trace`
+/// calls — is what walks the rest of the graph, the native stack used during
+/// a collection no longer grows with the depth of the object graph.
+///
+/// `'a` is the lifetime of the collection cycle itself, carried the same way
+/// `Gc<'gc, T>` carries `'gc`: anything enqueued through this context must
+/// stay valid for all of `'a`, not just for the duration of the `trace` call
+/// that enqueues it.
+#[derive(Clone, Copy)]
+pub struct CollectionContext<'a> {
+    tracer: &'a RefCell<Tracer<'a>>,
+}
+
+impl<'a> CollectionContext<'a> {
+    pub(crate) fn new(tracer: &'a RefCell<Tracer<'a>>) -> CollectionContext<'a> {
+        CollectionContext { tracer }
+    }
+
+    /// Queue `value` to have its `trace` called later, instead of calling it
+    /// now.
+    ///
+    /// `value` must stay valid for the rest of `'a`. For anything reached by
+    /// walking down from a root, that's guaranteed by the arena that owns
+    /// the allocation (outside this chunk), which keeps every reachable
+    /// value alive until sweeping; `enqueue` does not re-derive or check
+    /// that guarantee itself, which is why it requires `T: 'a` rather than
+    /// accepting a reference of arbitrary lifetime. Enqueuing the same
+    /// pointer more than once is fine: `Tracer` marks it the first time and
+    /// silently drops later duplicates, which is also what keeps a cyclic
+    /// object graph from being walked forever.
+    pub fn enqueue<T: Collect + 'a>(&self, value: &'a T) {
+        self.tracer.borrow_mut().enqueue(value as *const T as GcPtr<'a>);
+    }
+}
+
+/// Drain `tracer`, calling `trace` on every pointer it yields — including any
+/// further pointers those calls themselves enqueue — until the queue runs
+/// dry.
+///
+/// Each iteration pops a pointer into a local before calling `trace` on it,
+/// rather than matching on `tracer.borrow_mut().next()` directly: the latter
+/// would hold the `RefMut` for the rest of the loop body (a `while let`
+/// scrutinee's temporary lives for the whole loop, not just the match), and
+/// `trace` itself re-borrows `tracer` through `CollectionContext::enqueue`,
+/// which would panic on the reentrant borrow.
+pub(crate) fn trace_all<'a>(tracer: &'a RefCell<Tracer<'a>>) {
+    let cc = CollectionContext::new(tracer);
+    loop {
+        let next = tracer.borrow_mut().next();
+        let Some(ptr) = next else { break };
+        // SAFETY: see `CollectionContext::enqueue`.
+        unsafe { (*ptr).trace(cc) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    struct Node {
+        visits: Cell<usize>,
+        children: RefCell<Vec<*const Node>>,
+    }
+
+    unsafe impl Collect for Node {
+        fn trace(&self, cc: CollectionContext) {
+            self.visits.set(self.visits.get() + 1);
+            for &child in self.children.borrow().iter() {
+                // SAFETY: every `Node` in this test is leaked for `'static`.
+                let child: &'static Node = unsafe { &*child };
+                cc.enqueue(child);
+            }
+        }
+    }
+
+    #[test]
+    fn trace_all_visits_every_node_in_a_cycle_exactly_once() {
+        let a: &'static Node = Box::leak(Box::new(Node {
+            visits: Cell::new(0),
+            children: RefCell::new(Vec::new()),
+        }));
+        let b: &'static Node = Box::leak(Box::new(Node {
+            visits: Cell::new(0),
+            children: RefCell::new(vec![a as *const Node]),
+        }));
+        let c: &'static Node = Box::leak(Box::new(Node {
+            visits: Cell::new(0),
+            children: RefCell::new(vec![b as *const Node]),
+        }));
+        // Close the cycle: a -> c -> b -> a.
+        a.children.borrow_mut().push(c as *const Node);
+
+        let tracer = RefCell::new(Tracer::new());
+        tracer.borrow_mut().enqueue(a as *const Node as GcPtr<'static>);
+        trace_all(&tracer);
+
+        assert_eq!(a.visits.get(), 1);
+        assert_eq!(b.visits.get(), 1);
+        assert_eq!(c.visits.get(), 1);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::collect::Collect;
+
+/// An optional cleanup hook intended as a safe alternative to `Drop`.
+///
+/// The crate forbids `Drop` on `Collect` types (see [`MustNotImplDrop`](crate::no_drop::MustNotImplDrop))
+/// because a destructor could observe a `Gc` pointer after its pointee has
+/// already been freed. `finalize` is run through [`finalize_all`], which its
+/// caller hands the objects it has already found unreachable. Nothing in
+/// this crate calls `finalize_all` automatically during a collection, and
+/// `finalize_all` does not check reachability itself.
+///
+/// The contract a `finalize` implementation must uphold: it runs after the
+/// object is condemned but before it's freed, so its non-`Gc` state (a file
+/// handle, an external refcount) is still valid to clean up — but sibling
+/// garbage in the same cycle may already have been finalized and freed, with
+/// no ordering between one condemned object's finalization and another's
+/// beyond what `finalize_all` is given. A `finalize` implementation must not
+/// dereference any `Gc` it transitively owns, and must not resurrect `self`
+/// by stashing a pointer to it somewhere still reachable.
+pub trait Finalize: Collect {
+    fn finalize(&self) {}
+}
+
+/// Call `finalize` on every object in `condemned`, in the order given.
+///
+/// `finalize_all` does no reachability analysis; it just drives the hook
+/// once a caller has already decided what's condemned.
+pub fn finalize_all(condemned: &[&dyn Finalize]) {
+    for object in condemned {
+        object.finalize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    struct Counted<'a>(&'a Cell<usize>, Cell<Option<usize>>);
+
+    unsafe impl Collect for Counted<'_> {
+        fn needs_trace() -> bool {
+            false
+        }
+    }
+
+    impl Finalize for Counted<'_> {
+        fn finalize(&self) {
+            self.1.set(Some(self.0.get()));
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn finalize_all_runs_every_object_in_order() {
+        let calls = Cell::new(0);
+        let a = Counted(&calls, Cell::new(None));
+        let b = Counted(&calls, Cell::new(None));
+
+        finalize_all(&[&a, &b]);
+
+        assert_eq!(calls.get(), 2);
+        assert_eq!(a.1.get(), Some(0));
+        assert_eq!(b.1.get(), Some(1));
+    }
+}
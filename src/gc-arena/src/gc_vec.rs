@@ -0,0 +1,102 @@
+use crate::{Collect, GcCell, MutationContext};
+
+/// A `Vec` wrapped in a single `Gc`-managed allocation, removing the outer
+/// indirection of `Gc<GcCell<Vec<T>>>`.
+///
+/// The backing buffer is not an arena allocation: it is the `Vec`'s own
+/// buffer, and it grows or shrinks through the global allocator, exactly as
+/// it would behind `Gc<GcCell<Vec<T>>>`. Only the `GcCell` that holds the
+/// `Vec` is allocated through `MutationContext`. `Gc<GcCell<Vec<T>>>` puts
+/// an outer `Gc` box in front of the `GcCell`'s own allocation, so the
+/// collector has to chase a pointer to find the `Vec` at all; `GcVec` holds
+/// the `GcCell` directly, so the collector traces the `Vec` as one object.
+///
+/// There is deliberately no `std::ops::Index` impl: handing out a bare `&T`
+/// would let it outlive the only thing proving the buffer isn't being
+/// mutated concurrently. Use `get`, `iter`, or `with` instead.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct GcVec<'gc, T: Collect> {
+    cell: GcCell<'gc, Vec<T>>,
+}
+
+impl<'gc, T: Collect> GcVec<'gc, T> {
+    /// Allocate a new, empty `GcVec`.
+    pub fn new(mc: MutationContext<'gc, '_>) -> GcVec<'gc, T> {
+        GcVec {
+            cell: GcCell::allocate(mc, Vec::new()),
+        }
+    }
+
+    /// Allocate a new, empty `GcVec` with at least the given capacity.
+    pub fn with_capacity(mc: MutationContext<'gc, '_>, capacity: usize) -> GcVec<'gc, T> {
+        GcVec {
+            cell: GcCell::allocate(mc, Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Append a value to the end of the vector, reallocating the backing
+    /// buffer if there is no spare capacity.
+    pub fn push(&self, mc: MutationContext<'gc, '_>, value: T) {
+        self.cell.write(mc).push(value);
+    }
+
+    /// Remove and return the last value, if any.
+    pub fn pop(&self, mc: MutationContext<'gc, '_>) -> Option<T> {
+        self.cell.write(mc).pop()
+    }
+
+    /// Reserve capacity for at least `additional` more elements.
+    pub fn reserve(&self, mc: MutationContext<'gc, '_>, additional: usize) {
+        self.cell.write(mc).reserve(additional);
+    }
+
+    pub fn len(&self) -> usize {
+        self.cell.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cell.read().is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cell.read().capacity()
+    }
+
+    /// Fetch a clone of the element at `index`, if in bounds.
+    pub fn get(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.cell.read().get(index).cloned()
+    }
+
+    /// Run `f` with a borrow of the backing slice.
+    ///
+    /// This is the escape hatch for operations (iteration, indexing,
+    /// slicing) that can't be expressed through `get` alone, without handing
+    /// out a reference that could outlive the borrow.
+    pub fn with<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+        f(&self.cell.read())
+    }
+
+    /// Clone every element into a plain `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.cell.read().clone()
+    }
+
+    /// Iterate over a clone of every element.
+    ///
+    /// This yields owned values rather than references for the same reason
+    /// `get` does: a reference borrowed out of the `GcCell`'s guard can't
+    /// outlive this call without risking an alias on a later `push`/`pop`.
+    pub fn iter(&self) -> std::vec::IntoIter<T>
+    where
+        T: Clone,
+    {
+        self.to_vec().into_iter()
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::collect::Collect;
+
+/// A type-erased `Gc` pointer that has been queued for tracing but not yet
+/// visited.
+///
+/// Parameterized over `'a` the same way `Gc<'gc, T>` is, rather than
+/// defaulting to `dyn Collect + 'static`: the pointee is only ever valid for
+/// the lifetime of the arena that owns it, and erasing that down to
+/// `'static` would let a `Tracer` outlive the data it points at.
+pub(crate) type GcPtr<'a> = *const (dyn Collect + 'a);
+
+/// A breadth-first work queue that lets `CollectionContext` trace the object
+/// graph without recursing into `Collect::trace`.
+///
+/// Rather than a container's `trace` descending directly into the `trace` of
+/// each `Gc` it holds, it hands the pointee to a `Tracer`, which remembers it
+/// for later. The collector then drains the queue with `next`, invoking
+/// `trace` on each pointer in turn; since that call only enqueues further
+/// pointers instead of recursing into them, the native stack depth used
+/// during a collection no longer grows with the depth of the object graph.
+pub(crate) struct Tracer<'a> {
+    queued: HashSet<GcPtr<'a>>,
+    queue: VecDeque<GcPtr<'a>>,
+}
+
+impl<'a> Tracer<'a> {
+    pub(crate) fn new() -> Tracer<'a> {
+        Tracer {
+            queued: HashSet::new(),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Push a pointer onto the back of the work queue, unless it's already
+    /// been enqueued this cycle.
+    ///
+    /// Recording `ptr` in `queued` before it's ever dequeued is what lets a
+    /// cyclic object graph terminate instead of enqueuing the same node
+    /// forever, and what keeps a node reachable through more than one path
+    /// from being traced more than once.
+    pub(crate) fn enqueue(&mut self, ptr: GcPtr<'a>) {
+        if self.queued.insert(ptr) {
+            self.queue.push_back(ptr);
+        }
+    }
+
+    /// Pop the next pointer to trace, if any remain.
+    pub(crate) fn next(&mut self) -> Option<GcPtr<'a>> {
+        self.queue.pop_front()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
@@ -0,0 +1,209 @@
+use gc_arena::{Collect, MutationContext, StaticCollect};
+
+use crate::Sequence;
+
+/// Combinators for chaining [`Sequence`]s into composable, GC-safe pipelines.
+///
+/// Each combinator is its own `Sequence` implementation that steps its
+/// upstream sequence (and, where relevant, whatever sequence that produces)
+/// until it yields `Some`, returning `None` itself in the meantime so
+/// collection can safely happen between steps.
+pub trait SequenceExt<'gc>: Sequence<'gc> + Sized {
+    /// Transform the output of this sequence with a `'static` closure once it
+    /// completes.
+    fn map<F, R>(self, f: F) -> SequenceMap<Self, F>
+    where
+        F: 'static + FnOnce(MutationContext<'gc, '_>, Self::Output) -> R,
+    {
+        SequenceMap::new(self, f)
+    }
+
+    /// Once this sequence completes, feed its output to `f` to produce a new
+    /// sequence, which is then stepped to produce the final output.
+    fn and_then<F, S>(self, f: F) -> SequenceAndThen<Self, F, S>
+    where
+        F: 'static + FnOnce(MutationContext<'gc, '_>, Self::Output) -> S,
+        S: Sequence<'gc>,
+    {
+        SequenceAndThen::new(self, f)
+    }
+
+    /// Run `next` after this sequence completes, discarding this sequence's
+    /// output.
+    fn then<S>(self, next: S) -> SequenceThen<Self, S>
+    where
+        S: Sequence<'gc>,
+    {
+        SequenceThen::new(self, next)
+    }
+
+    /// Flatten a sequence whose output is itself a sequence, stepping the
+    /// inner sequence to produce the final output.
+    fn flatten(self) -> SequenceFlatten<Self, Self::Output>
+    where
+        Self::Output: Sequence<'gc>,
+    {
+        SequenceFlatten::new(self)
+    }
+}
+
+impl<'gc, S: Sequence<'gc>> SequenceExt<'gc> for S {}
+
+#[must_use = "sequences do nothing unless stepped"]
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub struct SequenceMap<S, F>(Option<(S, StaticCollect<F>)>);
+
+impl<S, F> SequenceMap<S, F> {
+    fn new(s: S, f: F) -> SequenceMap<S, F> {
+        SequenceMap(Some((s, StaticCollect(f))))
+    }
+}
+
+impl<'gc, S, F, R> Sequence<'gc> for SequenceMap<S, F>
+where
+    S: Sequence<'gc>,
+    F: 'static + FnOnce(MutationContext<'gc, '_>, S::Output) -> R,
+{
+    type Output = R;
+
+    fn step(&mut self, mc: MutationContext<'gc, '_>) -> Option<Self::Output> {
+        let (s, _) = self.0.as_mut().expect("cannot step a finished sequence");
+        let output = s.step(mc)?;
+        let (_, StaticCollect(f)) = self.0.take().unwrap();
+        Some(f(mc, output))
+    }
+}
+
+#[must_use = "sequences do nothing unless stepped"]
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub enum SequenceAndThen<S, F, C> {
+    First(S, StaticCollect<F>),
+    Second(C),
+    Done,
+}
+
+impl<S, F, C> SequenceAndThen<S, F, C> {
+    fn new(s: S, f: F) -> SequenceAndThen<S, F, C> {
+        SequenceAndThen::First(s, StaticCollect(f))
+    }
+}
+
+impl<'gc, S, F, C> Sequence<'gc> for SequenceAndThen<S, F, C>
+where
+    S: Sequence<'gc>,
+    F: 'static + FnOnce(MutationContext<'gc, '_>, S::Output) -> C,
+    C: Sequence<'gc>,
+{
+    type Output = C::Output;
+
+    fn step(&mut self, mc: MutationContext<'gc, '_>) -> Option<Self::Output> {
+        match std::mem::replace(self, SequenceAndThen::Done) {
+            SequenceAndThen::First(mut s, StaticCollect(f)) => {
+                if let Some(output) = s.step(mc) {
+                    *self = SequenceAndThen::Second(f(mc, output));
+                } else {
+                    *self = SequenceAndThen::First(s, StaticCollect(f));
+                }
+                None
+            }
+            SequenceAndThen::Second(mut c) => {
+                let output = c.step(mc);
+                if output.is_none() {
+                    *self = SequenceAndThen::Second(c);
+                }
+                output
+            }
+            SequenceAndThen::Done => panic!("cannot step a finished sequence"),
+        }
+    }
+}
+
+#[must_use = "sequences do nothing unless stepped"]
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub enum SequenceThen<S, N> {
+    First(S, N),
+    Second(N),
+    Done,
+}
+
+impl<S, N> SequenceThen<S, N> {
+    fn new(s: S, next: N) -> SequenceThen<S, N> {
+        SequenceThen::First(s, next)
+    }
+}
+
+impl<'gc, S, N> Sequence<'gc> for SequenceThen<S, N>
+where
+    S: Sequence<'gc>,
+    N: Sequence<'gc>,
+{
+    type Output = N::Output;
+
+    fn step(&mut self, mc: MutationContext<'gc, '_>) -> Option<Self::Output> {
+        match std::mem::replace(self, SequenceThen::Done) {
+            SequenceThen::First(mut s, next) => {
+                if s.step(mc).is_some() {
+                    *self = SequenceThen::Second(next);
+                } else {
+                    *self = SequenceThen::First(s, next);
+                }
+                None
+            }
+            SequenceThen::Second(mut next) => {
+                let output = next.step(mc);
+                if output.is_none() {
+                    *self = SequenceThen::Second(next);
+                }
+                output
+            }
+            SequenceThen::Done => panic!("cannot step a finished sequence"),
+        }
+    }
+}
+
+#[must_use = "sequences do nothing unless stepped"]
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub enum SequenceFlatten<S, I> {
+    Outer(S),
+    Inner(I),
+    Done,
+}
+
+impl<S, I> SequenceFlatten<S, I> {
+    fn new(s: S) -> SequenceFlatten<S, I> {
+        SequenceFlatten::Outer(s)
+    }
+}
+
+impl<'gc, S, I> Sequence<'gc> for SequenceFlatten<S, I>
+where
+    S: Sequence<'gc, Output = I>,
+    I: Sequence<'gc>,
+{
+    type Output = I::Output;
+
+    fn step(&mut self, mc: MutationContext<'gc, '_>) -> Option<Self::Output> {
+        match std::mem::replace(self, SequenceFlatten::Done) {
+            SequenceFlatten::Outer(mut s) => {
+                if let Some(inner) = s.step(mc) {
+                    *self = SequenceFlatten::Inner(inner);
+                } else {
+                    *self = SequenceFlatten::Outer(s);
+                }
+                None
+            }
+            SequenceFlatten::Inner(mut inner) => {
+                let output = inner.step(mc);
+                if output.is_none() {
+                    *self = SequenceFlatten::Inner(inner);
+                }
+                output
+            }
+            SequenceFlatten::Done => panic!("cannot step a finished sequence"),
+        }
+    }
+}